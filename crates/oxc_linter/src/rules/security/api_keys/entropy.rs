@@ -0,0 +1,40 @@
+/// Computes the [Shannon entropy](https://en.wikipedia.org/wiki/Entropy_(information_theory))
+/// of a byte sequence, in bits per character.
+///
+/// Random-looking strings (e.g. API keys, tokens) have high entropy, while natural language and
+/// repetitive strings have low entropy. This is used as a cheap heuristic to filter out
+/// candidates that are clearly not secrets before running more expensive, rule-specific checks.
+pub trait Entropy {
+    fn entropy(&self) -> f32;
+}
+
+impl Entropy for str {
+    fn entropy(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let mut counts = [0u32; 256];
+        for byte in self.bytes() {
+            counts[byte as usize] += 1;
+        }
+
+        let len = self.len() as f32;
+        counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f32 / len;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+#[test]
+fn test_entropy() {
+    assert_eq!("".entropy(), 0.0);
+    assert_eq!("aaaaaaaaaa".entropy(), 0.0);
+    assert!("abababab".entropy() < "a1b2c3d4".entropy());
+    assert!("aGVsbG8gd29ybGQ=".entropy() > "            ".entropy());
+}
@@ -0,0 +1,128 @@
+use std::num::NonZeroU32;
+
+use super::{Secret, SecretScanner, SecretScannerMeta};
+
+/// Which character set a [`Secret`] candidate is drawn from, used to pick the entropy
+/// threshold that's appropriate for that alphabet's size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alphabet {
+    Hex,
+    Base64,
+}
+
+/// Classifies `candidate`'s alphabet, preferring the narrowest one it cleanly fits: a string made
+/// up of only hex digits is `Hex` even though every hex digit is also a valid base64 character.
+fn classify(candidate: &str) -> Option<Alphabet> {
+    if candidate.is_empty() {
+        return None;
+    }
+    if candidate.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Some(Alphabet::Hex);
+    }
+    if candidate
+        .bytes()
+        .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'/' | b'-' | b'_' | b'='))
+    {
+        return Some(Alphabet::Base64);
+    }
+    None
+}
+
+/// Tunable length/entropy thresholds for [`GenericHighEntropy`], configurable through
+/// [`super::super::ApiKeysInner`] so consumers can adjust sensitivity for their codebase.
+#[derive(Debug, Clone, Copy)]
+pub struct GenericHighEntropyThresholds {
+    pub hex_min_len: NonZeroU32,
+    pub hex_min_entropy: f32,
+    pub base64_min_len: NonZeroU32,
+    pub base64_min_entropy: f32,
+}
+
+impl Default for GenericHighEntropyThresholds {
+    fn default() -> Self {
+        Self {
+            // SAFETY: 32 and 24 are valid values for NonZeroU32
+            hex_min_len: unsafe { NonZeroU32::new_unchecked(32) },
+            hex_min_entropy: 3.5,
+            base64_min_len: unsafe { NonZeroU32::new_unchecked(24) },
+            base64_min_entropy: 4.5,
+        }
+    }
+}
+
+/// Catch-all scanner for provider-agnostic credentials: any string literal that's cleanly hex,
+/// base64, or base64url and whose entropy clears the threshold for that alphabet.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GenericHighEntropy(pub GenericHighEntropyThresholds);
+
+impl SecretScannerMeta for GenericHighEntropy {
+    fn rule_name(&self) -> &'static str {
+        "generic-high-entropy"
+    }
+
+    fn message(&self) -> &'static str {
+        "Detected a high-entropy string, which may be a hard-coded secret or credential."
+    }
+
+    fn min_len(&self) -> NonZeroU32 {
+        self.0.hex_min_len.min(self.0.base64_min_len)
+    }
+
+    fn min_entropy(&self) -> f32 {
+        self.0.hex_min_entropy.min(self.0.base64_min_entropy)
+    }
+
+    /// Generic high-entropy strings are the rule most likely to miss a real secret purely on
+    /// entropy, so a suggestive binding name (`apiKey`, `authToken`, ...) is enough to lower
+    /// confidence and flag it.
+    fn name_hints(&self) -> &'static [&'static str] {
+        &["secret", "token", "apikey", "api_key", "api-key", "password", "auth"]
+    }
+}
+
+impl SecretScanner for GenericHighEntropy {
+    fn detect(&self, candidate: &Secret<'_>) -> bool {
+        // `min_entropy()` is the loosest of the two per-alphabet thresholds, so the gap between
+        // it and the (possibly discounted) `effective_min_entropy()` is how much to relax
+        // whichever alphabet-specific threshold actually applies below.
+        let discount = self.min_entropy() - self.effective_min_entropy(candidate.identifier());
+
+        match classify(candidate) {
+            Some(Alphabet::Hex) => {
+                candidate.len() >= self.0.hex_min_len.get() as usize
+                    && candidate.entropy() >= (self.0.hex_min_entropy - discount).max(0.0)
+            }
+            Some(Alphabet::Base64) => {
+                candidate.len() >= self.0.base64_min_len.get() as usize
+                    && candidate.entropy() >= (self.0.base64_min_entropy - discount).max(0.0)
+            }
+            None => false,
+        }
+    }
+}
+
+#[test]
+fn test_detect_discounts_hinted_candidate_below_min_entropy() {
+    use oxc_span::Span;
+
+    let rule = GenericHighEntropy(GenericHighEntropyThresholds {
+        hex_min_entropy: 4.2,
+        ..GenericHighEntropyThresholds::default()
+    });
+    // Clears `base64_min_entropy` (4.5) discounted by 1.0, but not the undiscounted value, and
+    // not `min_entropy()` (4.2, the hex floor) undiscounted either.
+    let secret = "dGhpc2lzYXRlc3RzdHJpbmcxMjM=";
+    let hinted = Secret::new(secret, Span::new(0, 0), Some("apiToken".into()));
+    let unhinted = Secret::new(secret, Span::new(0, 0), None);
+
+    assert!(rule.detect(&hinted));
+    assert!(!rule.detect(&unhinted));
+}
+
+#[test]
+fn test_classify() {
+    assert_eq!(classify(""), None);
+    assert_eq!(classify("deadbeef"), Some(Alphabet::Hex));
+    assert_eq!(classify("SGVsbG8sIHdvcmxkIQ=="), Some(Alphabet::Base64));
+    assert_eq!(classify("not_base64_at_all!"), None);
+}
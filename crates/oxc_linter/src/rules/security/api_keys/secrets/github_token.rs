@@ -0,0 +1,100 @@
+use std::num::NonZeroU32;
+
+use super::{Secret, SecretScanner, SecretScannerMeta, SecretViolation};
+
+/// Prefixes GitHub uses for its various token types, as documented at
+/// <https://docs.github.com/en/authentication/keeping-your-account-and-data-secure/about-authentication-to-github#githubs-token-formats>.
+const PREFIXES: [&str; 6] = ["ghp_", "gho_", "ghu_", "ghs_", "ghr_", "github_pat_"];
+
+/// Number of base62 characters making up the token body, not counting the prefix or checksum.
+const BODY_LEN: usize = 30;
+/// Number of base62 characters making up the trailing checksum.
+const CHECKSUM_LEN: usize = 6;
+
+const BASE62_ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+#[derive(Debug, Default, Clone)]
+pub struct GitHubToken;
+
+impl SecretScannerMeta for GitHubToken {
+    fn rule_name(&self) -> &'static str {
+        "github-token"
+    }
+
+    fn message(&self) -> &'static str {
+        "Detected a GitHub token, which can be used to access GitHub resources on behalf of the token's owner."
+    }
+
+    fn min_len(&self) -> NonZeroU32 {
+        // SAFETY: 40 is a valid value for NonZeroU32 (shortest prefix, `ghp_`, plus the
+        // 30-char body and 6-char checksum).
+        unsafe { NonZeroU32::new_unchecked(40) }
+    }
+}
+
+impl SecretScanner for GitHubToken {
+    fn detect(&self, candidate: &Secret<'_>) -> bool {
+        let Some(rest) = PREFIXES.iter().find_map(|prefix| candidate.strip_prefix(prefix)) else {
+            return false;
+        };
+        rest.len() == BODY_LEN + CHECKSUM_LEN && rest.bytes().all(is_base62)
+    }
+
+    fn verify(&self, violation: &mut SecretViolation<'_>) -> bool {
+        let rest = PREFIXES
+            .iter()
+            .find_map(|prefix| violation.secret().strip_prefix(prefix))
+            .expect("verify is only called after detect succeeds");
+        let (body, checksum) = rest.split_at(BODY_LEN);
+
+        let expected = base62_encode(crc32(body.as_bytes()));
+        checksum == expected
+    }
+}
+
+fn is_base62(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+}
+
+/// Encodes `value` as base62 (alphabet `0-9A-Za-z`, most-significant digit first), left-padded
+/// with `0` to [`CHECKSUM_LEN`] characters.
+fn base62_encode(mut value: u32) -> String {
+    let mut digits = Vec::with_capacity(CHECKSUM_LEN);
+    if value == 0 {
+        digits.push(BASE62_ALPHABET[0]);
+    }
+    while value > 0 {
+        digits.push(BASE62_ALPHABET[(value % 62) as usize]);
+        value /= 62;
+    }
+    digits.reverse();
+
+    let mut encoded = String::from("0".repeat(CHECKSUM_LEN.saturating_sub(digits.len())));
+    encoded.push_str(std::str::from_utf8(&digits).unwrap());
+    encoded
+}
+
+/// CRC-32 (IEEE 802.3 polynomial), matching the checksum algorithm GitHub embeds in its tokens.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+#[test]
+fn test_base62_encode() {
+    assert_eq!(base62_encode(0), "000000");
+    assert_eq!(base62_encode(61), "00000z");
+    assert_eq!(base62_encode(62), "000010");
+}
+
+#[test]
+fn test_crc32() {
+    // Well-known test vector: CRC-32 of the ASCII string "123456789" is 0xCBF43926.
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+}
@@ -0,0 +1,72 @@
+mod aws_access_key_id;
+mod generic_high_entropy;
+mod github_token;
+
+pub use aws_access_key_id::AwsAccessKeyId;
+pub use generic_high_entropy::{GenericHighEntropy, GenericHighEntropyThresholds};
+pub use github_token::GitHubToken;
+
+use super::{Secret, SecretScanner, SecretScannerMeta, SecretViolation};
+
+/// Declares the `SecretsEnum` dispatch enum plus the `ALL_RULES` list from a set of
+/// [`SecretScanner`] implementors, so adding a new rule only means adding it to this list.
+macro_rules! secrets_enum {
+    ($($variant:ident),+ $(,)?) => {
+        #[derive(Debug, Clone)]
+        pub enum SecretsEnum {
+            $($variant($variant)),+
+        }
+
+        impl SecretScannerMeta for SecretsEnum {
+            fn rule_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(rule) => rule.rule_name()),+
+                }
+            }
+
+            fn message(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(rule) => rule.message()),+
+                }
+            }
+
+            fn min_len(&self) -> std::num::NonZeroU32 {
+                match self {
+                    $(Self::$variant(rule) => rule.min_len()),+
+                }
+            }
+
+            fn min_entropy(&self) -> f32 {
+                match self {
+                    $(Self::$variant(rule) => rule.min_entropy()),+
+                }
+            }
+
+            fn name_hints(&self) -> &'static [&'static str] {
+                match self {
+                    $(Self::$variant(rule) => rule.name_hints()),+
+                }
+            }
+        }
+
+        impl SecretScanner for SecretsEnum {
+            fn detect(&self, candidate: &Secret<'_>) -> bool {
+                match self {
+                    $(Self::$variant(rule) => rule.detect(candidate)),+
+                }
+            }
+
+            fn verify(&self, violation: &mut SecretViolation<'_>) -> bool {
+                match self {
+                    $(Self::$variant(rule) => rule.verify(violation)),+
+                }
+            }
+        }
+
+        /// All known secret-scanning rules, in order of cheapest to most expensive to run.
+        pub static ALL_RULES: std::sync::LazyLock<Vec<SecretsEnum>> =
+            std::sync::LazyLock::new(|| vec![$(SecretsEnum::$variant($variant::default())),+]);
+    };
+}
+
+secrets_enum!(AwsAccessKeyId, GitHubToken, GenericHighEntropy);